@@ -0,0 +1,134 @@
+use std::{fmt, str::FromStr};
+
+use headers_core::{self, Header, HeaderName, HeaderValue};
+
+use crate::coding::Coding;
+use crate::token_header::TokenHeader;
+use crate::Quality;
+
+pub use crate::token_header::ParseError;
+
+/// This header lets the client specify which character encodings it can handle.
+///
+/// See [its specification in RFC9110](https://www.rfc-editor.org/rfc/rfc9110#name-accept-charset).
+#[derive(Debug, Clone)]
+pub struct AcceptCharset(TokenHeader);
+
+impl AcceptCharset {
+    pub const HEADER_NAME: &str = "accept-charset";
+
+    /// Parse the complete header, including the header name.
+    ///
+    /// Parsing will fail unless the header starts with (case insensitive) `accept-charset:`.
+    pub fn parse(header: &str) -> Result<Self, ParseError> {
+        TokenHeader::parse(Self::HEADER_NAME, header).map(Self)
+    }
+
+    /// Parse the header body, excluding the header name.
+    pub fn parse_body(body: &str) -> Result<Self, ParseError> {
+        TokenHeader::parse_body(Self::HEADER_NAME, body).map(Self)
+    }
+
+    /// Construct an `AcceptCharset` from a collection of charsets, sorting them into the
+    /// descending-quality order this type maintains internally.
+    pub fn new(charsets: impl IntoIterator<Item = Coding>) -> Self {
+        Self(TokenHeader::new(Self::HEADER_NAME, charsets))
+    }
+
+    /// Insert a charset, maintaining descending-quality order.
+    pub fn push(&mut self, charset: Coding) {
+        self.0.push(charset);
+    }
+
+    /// Builder-style variant of [`push`](Self::push).
+    pub fn with(mut self, charset: Coding) -> Self {
+        self.push(charset);
+        self
+    }
+
+    /// Iterate over the acceptable charsets, from highest to lowest priority.
+    pub fn charsets(&self) -> impl '_ + Iterator<Item = &Coding> {
+        self.0.iter()
+    }
+
+    /// Perform server-side content negotiation: given the charsets a server is able to offer,
+    /// pick the one this `Accept-Charset` header most prefers. Returns `None` if none of the
+    /// offered charsets are acceptable. See [`Accept::negotiate`](crate::Accept::negotiate) for
+    /// the full selection rules.
+    pub fn negotiate<'a>(&self, offered: &'a [String]) -> Option<&'a String> {
+        self.negotiate_with_quality(offered).map(|(charset, _)| charset)
+    }
+
+    /// As [`negotiate`](Self::negotiate), but also returns the matched [`Quality`].
+    pub fn negotiate_with_quality<'a>(
+        &self,
+        offered: &'a [String],
+    ) -> Option<(&'a String, Quality)> {
+        self.0.negotiate_with_quality(offered)
+    }
+
+    /// Emit the header's body without its header.
+    pub fn body_to_string(&self) -> String {
+        self.0.body_to_string()
+    }
+}
+
+impl FromStr for AcceptCharset {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for AcceptCharset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// best-guess implementation; see <https://github.com/hyperium/headers/issues/144>
+impl Header for AcceptCharset {
+    fn name() -> &'static HeaderName {
+        static NAME: HeaderName = HeaderName::from_static(AcceptCharset::HEADER_NAME);
+        &NAME
+    }
+
+    fn decode<'i, I>(values: &mut I) -> Result<Self, headers_core::Error>
+    where
+        Self: Sized,
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        TokenHeader::decode(Self::HEADER_NAME, values).map(Self)
+    }
+
+    fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+        self.0.encode(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_example() {
+        let input = "Accept-Charset: iso-8859-5, unicode-1-1;q=0.8";
+        let accept = AcceptCharset::parse(input).unwrap();
+        let charsets: Vec<_> = accept.charsets().map(|c| c.token.as_str()).collect();
+        assert_eq!(charsets, ["iso-8859-5", "unicode-1-1"]);
+    }
+
+    #[test]
+    fn negotiate_matches_case_insensitively() {
+        let accept = AcceptCharset::parse("Accept-Charset: UTF-8;q=1.0, *;q=0.1").unwrap();
+        let offered = ["utf-8".to_string(), "ascii".to_string()];
+        assert_eq!(accept.negotiate(&offered), Some(&offered[0]));
+    }
+
+    #[test]
+    fn builder_roundtrip() {
+        let accept = AcceptCharset::new([]).with(Coding::new("utf-8", None));
+        assert_eq!(accept.body_to_string(), "utf-8");
+    }
+}
@@ -0,0 +1,145 @@
+use std::{fmt, str::FromStr};
+
+use headers_core::{self, Header, HeaderName, HeaderValue};
+
+use crate::coding::Coding;
+use crate::token_header::TokenHeader;
+use crate::Quality;
+
+pub use crate::token_header::ParseError;
+
+/// This header lets the client specify which content codings it can handle.
+///
+/// See [its specification in RFC9110](https://www.rfc-editor.org/rfc/rfc9110#name-accept-encoding).
+#[derive(Debug, Clone)]
+pub struct AcceptEncoding(TokenHeader);
+
+impl AcceptEncoding {
+    pub const HEADER_NAME: &str = "accept-encoding";
+
+    /// Parse the complete header, including the header name.
+    ///
+    /// Parsing will fail unless the header starts with (case insensitive) `accept-encoding:`.
+    pub fn parse(header: &str) -> Result<Self, ParseError> {
+        TokenHeader::parse(Self::HEADER_NAME, header).map(Self)
+    }
+
+    /// Parse the header body, excluding the header name.
+    pub fn parse_body(body: &str) -> Result<Self, ParseError> {
+        TokenHeader::parse_body(Self::HEADER_NAME, body).map(Self)
+    }
+
+    /// Construct an `AcceptEncoding` from a collection of codings, sorting them into the
+    /// descending-quality order this type maintains internally.
+    pub fn new(codings: impl IntoIterator<Item = Coding>) -> Self {
+        Self(TokenHeader::new(Self::HEADER_NAME, codings))
+    }
+
+    /// Insert a coding, maintaining descending-quality order.
+    pub fn push(&mut self, coding: Coding) {
+        self.0.push(coding);
+    }
+
+    /// Builder-style variant of [`push`](Self::push).
+    pub fn with(mut self, coding: Coding) -> Self {
+        self.push(coding);
+        self
+    }
+
+    /// Iterate over the acceptable codings, from highest to lowest priority.
+    pub fn codings(&self) -> impl '_ + Iterator<Item = &Coding> {
+        self.0.iter()
+    }
+
+    /// Perform server-side content negotiation: given the content codings a server is able to
+    /// offer, pick the one this `Accept-Encoding` header most prefers. Returns `None` if none of
+    /// the offered codings are acceptable. See [`Accept::negotiate`](crate::Accept::negotiate)
+    /// for the full selection rules.
+    pub fn negotiate<'a>(&self, offered: &'a [String]) -> Option<&'a String> {
+        self.negotiate_with_quality(offered).map(|(coding, _)| coding)
+    }
+
+    /// As [`negotiate`](Self::negotiate), but also returns the matched [`Quality`].
+    pub fn negotiate_with_quality<'a>(
+        &self,
+        offered: &'a [String],
+    ) -> Option<(&'a String, Quality)> {
+        self.0.negotiate_with_quality(offered)
+    }
+
+    /// Emit the header's body without its header.
+    pub fn body_to_string(&self) -> String {
+        self.0.body_to_string()
+    }
+}
+
+impl FromStr for AcceptEncoding {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for AcceptEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// best-guess implementation; see <https://github.com/hyperium/headers/issues/144>
+impl Header for AcceptEncoding {
+    fn name() -> &'static HeaderName {
+        static NAME: HeaderName = HeaderName::from_static(AcceptEncoding::HEADER_NAME);
+        &NAME
+    }
+
+    fn decode<'i, I>(values: &mut I) -> Result<Self, headers_core::Error>
+    where
+        Self: Sized,
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        TokenHeader::decode(Self::HEADER_NAME, values).map(Self)
+    }
+
+    fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+        self.0.encode(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_example() {
+        let input = "Accept-Encoding: gzip;q=1.0, identity;q=0.5, *;q=0";
+        let accept = AcceptEncoding::parse(input).unwrap();
+        let codings: Vec<_> = accept.codings().map(|c| c.token.as_str()).collect();
+        assert_eq!(codings, ["gzip", "identity", "*"]);
+    }
+
+    #[test]
+    fn negotiate_picks_highest_scoring_offer() {
+        let accept = AcceptEncoding::parse("Accept-Encoding: gzip;q=1.0, *;q=0.5").unwrap();
+        let offered = ["br".to_string(), "gzip".to_string()];
+        assert_eq!(accept.negotiate(&offered), Some(&offered[1]));
+    }
+
+    #[test]
+    fn negotiate_excludes_zero_quality() {
+        let accept = AcceptEncoding::parse("Accept-Encoding: gzip;q=0, *;q=0.3").unwrap();
+        let offered = ["gzip".to_string()];
+        assert_eq!(accept.negotiate(&offered), None);
+    }
+
+    #[test]
+    fn builder_roundtrip() {
+        let accept =
+            AcceptEncoding::new([]).with(Coding::new("gzip", None)).with(Coding::new(
+                "br",
+                Quality::try_new(0.5),
+            ));
+        assert_eq!(accept.body_to_string(), "gzip, br;q=0.5");
+    }
+}
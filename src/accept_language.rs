@@ -0,0 +1,361 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use bstr::BString;
+use headers_core::{self, Header, HeaderName, HeaderValue};
+
+use crate::weighted_list::{RangeSpec, WeightedList};
+use crate::Quality;
+
+/// A single weighted entry in an `Accept-Language` header: a language range (e.g. `en`, `en-US`,
+/// or the wildcard `*`) together with an optional quality weight.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageRange {
+    pub tag: String,
+    pub quality: Option<Quality>,
+}
+
+impl LanguageRange {
+    /// The quality factor for a language range is 1.0 if not explicitly specified with the `q` parameter.
+    #[inline]
+    pub fn quality_factor(&self) -> Quality {
+        self.quality.unwrap_or(Quality::new(1.0))
+    }
+
+    /// Construct a `LanguageRange` from a tag and an explicit quality weight, clamped to `[0.0..=1.0]`.
+    pub fn new(tag: impl Into<String>, quality: Option<Quality>) -> Self {
+        let quality = quality.map(|q| Quality::new(q.raw().clamp(0.0, 1.0)));
+        LanguageRange {
+            tag: tag.into(),
+            quality,
+        }
+    }
+
+    fn is_wildcard(&self) -> bool {
+        self.tag == "*"
+    }
+
+    /// Does this range match the given concrete language tag? `*` matches anything; otherwise a
+    /// range matches a tag that is case-insensitively equal to it, or that it is a case-insensitive
+    /// prefix of at a `-` boundary (so `en` matches `en-US`).
+    pub(crate) fn matches(&self, concrete: &str) -> bool {
+        if self.is_wildcard() {
+            return true;
+        }
+        if self.tag.eq_ignore_ascii_case(concrete) {
+            return true;
+        }
+        concrete
+            .get(..self.tag.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&self.tag))
+            && concrete.as_bytes().get(self.tag.len()) == Some(&b'-')
+    }
+}
+
+impl FromStr for LanguageRange {
+    type Err = LanguageRangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(';').map(str::trim);
+        let tag = parts.next().filter(|tag| !tag.is_empty()).ok_or(
+            LanguageRangeParseError::EmptyTag {
+                input: s.to_string(),
+            },
+        )?;
+
+        let mut quality = None;
+        for param in parts {
+            if let Some(q) = param.strip_prefix("q=").or_else(|| param.strip_prefix("Q=")) {
+                quality = q
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .map(|f| f.clamp(0.0, 1.0))
+                    .and_then(Quality::try_new);
+            }
+        }
+
+        Ok(LanguageRange {
+            tag: tag.to_string(),
+            quality,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LanguageRangeParseError {
+    #[error("language range \"{input}\" has an empty tag")]
+    EmptyTag { input: String },
+}
+
+impl Ord for LanguageRange {
+    /// Language ranges compare by the following rules:
+    ///
+    /// 1. `quality_factor()`
+    /// 2. the wildcard `*` is less than any explicit tag
+    /// 3. `Reverse(tag)`, case insensitively, for the same reason `MediaType` reverses its essence string
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.quality_factor()
+            .cmp(&other.quality_factor())
+            .then_with(|| match (self.is_wildcard(), other.is_wildcard()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    if self.tag.eq_ignore_ascii_case(&other.tag) {
+                        Ordering::Equal
+                    } else {
+                        self.tag
+                            .to_ascii_lowercase()
+                            .cmp(&other.tag.to_ascii_lowercase())
+                            .reverse()
+                    }
+                }
+            })
+    }
+}
+
+impl PartialOrd for LanguageRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RangeSpec for LanguageRange {
+    type Concrete = String;
+
+    fn quality_factor(&self) -> Quality {
+        self.quality_factor()
+    }
+
+    fn matches(&self, concrete: &String) -> bool {
+        self.matches(concrete.as_str())
+    }
+
+    fn precedence(&self) -> u32 {
+        if self.is_wildcard() {
+            0
+        } else {
+            1 + self.tag.len() as u32
+        }
+    }
+}
+
+impl fmt::Display for LanguageRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tag)?;
+        if let Some(quality) = self.quality {
+            write!(f, ";q={quality}")?;
+        }
+        Ok(())
+    }
+}
+
+/// This header lets the client specify which natural languages it prefers for the response.
+///
+/// See [its specification in RFC9110](https://www.rfc-editor.org/rfc/rfc9110#name-accept-language).
+#[derive(Debug, Clone)]
+pub struct AcceptLanguage {
+    /// This is always stored in descending order of quality.
+    languages: WeightedList<LanguageRange>,
+}
+
+impl AcceptLanguage {
+    pub const HEADER_NAME: &str = "accept-language";
+
+    /// Parse the complete header, including the header name.
+    ///
+    /// Parsing will fail unless the header starts with (case insensitive) `accept-language:`.
+    pub fn parse(header: &str) -> Result<Self, ParseError> {
+        const HEADER_IDX: usize = AcceptLanguage::HEADER_NAME.len();
+        const HEADER_COLON_IDX: usize = HEADER_IDX + 1;
+
+        let expect_substr = &header.as_bytes()[..HEADER_IDX.min(header.len())];
+        if !expect_substr.eq_ignore_ascii_case(Self::HEADER_NAME.as_bytes()) {
+            return Err(ParseError::WrongHeader(expect_substr.into()));
+        }
+
+        if !header.is_char_boundary(HEADER_COLON_IDX) {
+            return Err(ParseError::BodyIndexNotOnCharacterBoundary);
+        }
+
+        Self::parse_body(&header[HEADER_COLON_IDX..])
+    }
+
+    /// Parse the header body, excluding the header name.
+    pub fn parse_body(body: &str) -> Result<Self, ParseError> {
+        let mut languages = Vec::new();
+        for input in body.split(',') {
+            let input = input.trim();
+            let language = input
+                .parse::<LanguageRange>()
+                .map_err(|err| ParseError::FailedToParseLanguageRange {
+                    input: input.into(),
+                    err,
+                })?;
+            languages.push(language);
+        }
+
+        Ok(Self {
+            languages: WeightedList::new(languages),
+        })
+    }
+
+    /// Construct an `AcceptLanguage` from a collection of language ranges, sorting them into the
+    /// descending-quality order this type maintains internally.
+    pub fn new(languages: impl IntoIterator<Item = LanguageRange>) -> Self {
+        Self {
+            languages: WeightedList::new(languages),
+        }
+    }
+
+    /// Insert a language range, maintaining descending-quality order.
+    pub fn push(&mut self, language: LanguageRange) {
+        self.languages.push(language);
+    }
+
+    /// Builder-style variant of [`push`](Self::push).
+    pub fn with(mut self, language: LanguageRange) -> Self {
+        self.push(language);
+        self
+    }
+
+    /// Iterate over the acceptable language ranges, from highest to lowest priority.
+    pub fn languages(&self) -> impl '_ + Iterator<Item = &LanguageRange> {
+        self.languages.iter()
+    }
+
+    /// Perform server-side content negotiation: given the language tags a server is able to
+    /// offer, pick the one this `Accept-Language` header most prefers. A range matches a tag that
+    /// is equal to it or that it is a prefix of at a `-` boundary (so `en` matches `en-US`).
+    /// Returns `None` if none of the offered tags are acceptable. See
+    /// [`Accept::negotiate`](crate::Accept::negotiate) for the full selection rules.
+    pub fn negotiate<'a>(&self, offered: &'a [String]) -> Option<&'a String> {
+        self.negotiate_with_quality(offered).map(|(tag, _)| tag)
+    }
+
+    /// As [`negotiate`](Self::negotiate), but also returns the matched [`Quality`].
+    pub fn negotiate_with_quality<'a>(
+        &self,
+        offered: &'a [String],
+    ) -> Option<(&'a String, Quality)> {
+        self.languages.negotiate(offered)
+    }
+
+    /// Create a formatter which formats only the body of this type.
+    fn fmt_body(&self) -> FormatBody<'_> {
+        FormatBody {
+            languages: self.languages.as_slice(),
+        }
+    }
+
+    /// Emit the header's body without its header.
+    pub fn body_to_string(&self) -> String {
+        self.fmt_body().to_string()
+    }
+}
+
+impl FromStr for AcceptLanguage {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for AcceptLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "accept-language: ")?;
+        self.fmt_body().fmt(f)
+    }
+}
+
+// best-guess implementation; see <https://github.com/hyperium/headers/issues/144>
+impl Header for AcceptLanguage {
+    fn name() -> &'static HeaderName {
+        static NAME: HeaderName = HeaderName::from_static(AcceptLanguage::HEADER_NAME);
+        &NAME
+    }
+
+    fn decode<'i, I>(values: &mut I) -> Result<Self, headers_core::Error>
+    where
+        Self: Sized,
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        let value = values.next().ok_or_else(headers_core::Error::invalid)?;
+        let value_str = value.to_str().map_err(|_| headers_core::Error::invalid())?;
+        AcceptLanguage::parse_body(value_str).map_err(|_| headers_core::Error::invalid())
+    }
+
+    fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+        let header_value = HeaderValue::from_str(&self.body_to_string())
+            .expect("header canonical form includes only visible ascii chars");
+        values.extend(::std::iter::once(header_value));
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FormatBody<'a> {
+    languages: &'a [LanguageRange],
+}
+
+impl<'a> fmt::Display for FormatBody<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for language in self.languages {
+            if first {
+                first = false;
+            } else {
+                f.write_str(", ")?;
+            }
+            language.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("wrong header name: expect \"accept-language\"; have \"{0}\"")]
+    WrongHeader(BString),
+    #[error("body index does not fall on a character boundary")]
+    BodyIndexNotOnCharacterBoundary,
+    #[error("failed to parse language range \"{input}\"")]
+    FailedToParseLanguageRange {
+        input: String,
+        #[source]
+        err: LanguageRangeParseError,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_example() {
+        let input = "Accept-Language: da, en-gb;q=0.8, en;q=0.7";
+        let accept = AcceptLanguage::parse(input).unwrap();
+        let tags: Vec<_> = accept.languages().map(|l| l.tag.as_str()).collect();
+        assert_eq!(tags, ["da", "en-gb", "en"]);
+    }
+
+    #[test]
+    fn negotiate_matches_prefix() {
+        let accept = AcceptLanguage::parse("Accept-Language: en;q=1.0, fr;q=0.5").unwrap();
+        let offered = ["en-US".to_string(), "fr".to_string()];
+        assert_eq!(accept.negotiate(&offered), Some(&offered[0]));
+    }
+
+    #[test]
+    fn exact_match_outscores_lower_quality_wildcard() {
+        let accept = AcceptLanguage::parse("Accept-Language: *;q=0.1, en;q=0.5").unwrap();
+        let offered = ["de".to_string(), "en".to_string()];
+        assert_eq!(accept.negotiate(&offered), Some(&offered[1]));
+    }
+
+    #[test]
+    fn builder_roundtrip() {
+        let accept = AcceptLanguage::new([]).with(LanguageRange::new("en", None));
+        assert_eq!(accept.body_to_string(), "en");
+    }
+}
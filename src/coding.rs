@@ -0,0 +1,134 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::weighted_list::RangeSpec;
+use crate::Quality;
+
+/// A single weighted entry in an `Accept-Encoding` or `Accept-Charset` header: a token
+/// (e.g. `gzip`, `utf-8`, or the wildcard `*`) together with an optional quality weight.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Coding {
+    pub token: String,
+    pub quality: Option<Quality>,
+}
+
+impl Coding {
+    /// The quality factor for a coding is 1.0 if not explicitly specified with the `q` parameter.
+    #[inline]
+    pub fn quality_factor(&self) -> Quality {
+        self.quality.unwrap_or(Quality::new(1.0))
+    }
+
+    /// Construct a `Coding` from a token and an explicit quality weight, clamped to `[0.0..=1.0]`.
+    pub fn new(token: impl Into<String>, quality: Option<Quality>) -> Self {
+        let quality = quality.map(|q| Quality::new(q.raw().clamp(0.0, 1.0)));
+        Coding {
+            token: token.into(),
+            quality,
+        }
+    }
+
+    fn is_wildcard(&self) -> bool {
+        self.token == "*"
+    }
+
+    /// Does this range match the given concrete token? `*` matches anything; otherwise the
+    /// comparison is case insensitive.
+    pub(crate) fn matches(&self, concrete: &str) -> bool {
+        self.is_wildcard() || self.token.eq_ignore_ascii_case(concrete)
+    }
+}
+
+impl FromStr for Coding {
+    type Err = CodingParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(';').map(str::trim);
+        let token = parts.next().filter(|token| !token.is_empty()).ok_or(
+            CodingParseError::EmptyToken {
+                input: s.to_string(),
+            },
+        )?;
+
+        let mut quality = None;
+        for param in parts {
+            if let Some(q) = param.strip_prefix("q=").or_else(|| param.strip_prefix("Q=")) {
+                quality = q
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .map(|f| f.clamp(0.0, 1.0))
+                    .and_then(Quality::try_new);
+            }
+        }
+
+        Ok(Coding {
+            token: token.to_string(),
+            quality,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CodingParseError {
+    #[error("coding \"{input}\" has an empty token")]
+    EmptyToken { input: String },
+}
+
+impl Ord for Coding {
+    /// Codings compare by the following rules:
+    ///
+    /// 1. `quality_factor()`
+    /// 2. the wildcard `*` is less than any explicit token
+    /// 3. `Reverse(token)`, case insensitively, for the same reason `MediaType` reverses its essence string
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.quality_factor()
+            .cmp(&other.quality_factor())
+            .then_with(|| match (self.is_wildcard(), other.is_wildcard()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    if self.token.eq_ignore_ascii_case(&other.token) {
+                        Ordering::Equal
+                    } else {
+                        self.token
+                            .to_ascii_lowercase()
+                            .cmp(&other.token.to_ascii_lowercase())
+                            .reverse()
+                    }
+                }
+            })
+    }
+}
+
+impl PartialOrd for Coding {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RangeSpec for Coding {
+    type Concrete = String;
+
+    fn quality_factor(&self) -> Quality {
+        self.quality_factor()
+    }
+
+    fn matches(&self, concrete: &String) -> bool {
+        self.matches(concrete.as_str())
+    }
+
+    fn precedence(&self) -> u32 {
+        u32::from(!self.is_wildcard())
+    }
+}
+
+impl fmt::Display for Coding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.token)?;
+        if let Some(quality) = self.quality {
+            write!(f, ";q={quality}")?;
+        }
+        Ok(())
+    }
+}
@@ -5,8 +5,20 @@ use headers_core::{self, Header, HeaderName, HeaderValue};
 use mime::Mime;
 use noisy_float::types::R32;
 
+mod accept_charset;
+mod accept_encoding;
+mod accept_language;
+mod coding;
 mod media_type;
-use media_type::MediaType;
+mod token_header;
+mod weighted_list;
+
+pub use accept_charset::AcceptCharset;
+pub use accept_encoding::AcceptEncoding;
+pub use accept_language::{AcceptLanguage, LanguageRange};
+pub use coding::Coding;
+pub use media_type::MediaType;
+use weighted_list::WeightedList;
 
 /// `Quality` is a real number in the (inclusive) range `[0.0..=1.0]`.
 pub type Quality = R32;
@@ -17,7 +29,7 @@ pub type Quality = R32;
 #[derive(Debug, Clone)]
 pub struct Accept {
     /// This is always stored in descending order of quality.
-    media_types: Vec<MediaType>,
+    media_types: WeightedList<MediaType>,
 }
 
 impl Accept {
@@ -57,11 +69,28 @@ impl Accept {
             media_types.push(mime.into())
         }
 
-        // maintain descending order by relevance
-        media_types.sort();
-        media_types.reverse();
+        Ok(Self {
+            media_types: WeightedList::new(media_types),
+        })
+    }
+
+    /// Construct an `Accept` from a collection of media types, sorting them into the
+    /// descending-quality order this type maintains internally.
+    pub fn new(types: impl IntoIterator<Item = MediaType>) -> Self {
+        Self {
+            media_types: WeightedList::new(types),
+        }
+    }
+
+    /// Insert a media type, maintaining descending-quality order.
+    pub fn push(&mut self, media_type: MediaType) {
+        self.media_types.push(media_type);
+    }
 
-        Ok(Self { media_types })
+    /// Builder-style variant of [`push`](Self::push).
+    pub fn with(mut self, media_type: MediaType) -> Self {
+        self.push(media_type);
+        self
     }
 
     /// Iterate over the acceptable media types, from highest to lowest priority.
@@ -69,10 +98,29 @@ impl Accept {
         self.media_types.iter()
     }
 
+    /// Perform server-side content negotiation: given the media types a server is able to offer,
+    /// pick the one this `Accept` header most prefers.
+    ///
+    /// For each offered type, every media range in this header which [matches](MediaType::matches)
+    /// it is considered, and the most specific matching range (exact `type/subtype` beats
+    /// `type/*` beats `*/*`) determines that type's score. A matching range with quality `0.0`
+    /// explicitly rejects the offered type. The offered type with the highest score wins, ties
+    /// being broken in favor of the type appearing earliest in `offered` (i.e. server preference).
+    ///
+    /// Returns `None` if none of the offered types are acceptable.
+    pub fn negotiate<'a>(&self, offered: &'a [Mime]) -> Option<&'a Mime> {
+        self.negotiate_with_quality(offered).map(|(mime, _)| mime)
+    }
+
+    /// As [`negotiate`](Self::negotiate), but also returns the matched [`Quality`].
+    pub fn negotiate_with_quality<'a>(&self, offered: &'a [Mime]) -> Option<(&'a Mime, Quality)> {
+        self.media_types.negotiate(offered)
+    }
+
     /// Create a formatter which formats only the body of this type.
-    fn fmt_body(&self) -> FormatBody {
+    fn fmt_body(&self) -> FormatBody<'_> {
         FormatBody {
-            media_types: &self.media_types,
+            media_types: self.media_types.as_slice(),
         }
     }
 
@@ -241,4 +289,13 @@ mod tests {
         let expect = &[("application/json", 1.0, None)];
         perform_test(input, expect);
     }
+
+    #[test]
+    fn builder_roundtrip_preserves_quality() {
+        let accept = Accept::new([]).with(MediaType::new(
+            "text/html".parse().unwrap(),
+            Quality::try_new(0.8),
+        ));
+        assert_eq!(accept.body_to_string(), "text/html;q=0.8");
+    }
 }
@@ -2,6 +2,7 @@ use std::{cmp::Ordering, fmt};
 
 use mime::{Mime, Name};
 
+use crate::weighted_list::RangeSpec;
 use crate::Quality;
 
 /// A Media Type combines a media range (including parameters) with a quality weight.
@@ -21,6 +22,101 @@ impl MediaType {
     }
 }
 
+/// Compare two mime names, returning an ordering.
+///
+/// We can't compare them directly because the derived implementation doesn't
+/// respect case insensitivity, and also doesn't handle the "wildcard is least"
+/// rule that we want.
+fn compare_names(a: Name, b: Name) -> Ordering {
+    match (a, b) {
+        (mime::STAR, mime::STAR) => Ordering::Equal,
+        (mime::STAR, _) => Ordering::Less,
+        (_, mime::STAR) => Ordering::Greater,
+        (a, b) => {
+            // this _may_ be equal even if the source strings are unequal, if it happens that
+            // we have a case-insensitive comparison. Unfortunately, the library doesn't expose
+            // that information to us directly, so we have to explicitly compare for equality before
+            // performing an ordering comparison.
+            //
+            // ... the _only_ way to get at a safe public test which respects case insensitivity when appropriate
+            // is to compare a `Name` to a `&str`.
+            if a == b.as_str() {
+                Ordering::Equal
+            } else {
+                // if they're unequal, by whatever metric, it's fine to fall back to string comparison.
+                // note though that we reverse the output: this lets us sort a list of media types, and the
+                // best one (all else being equal) is the first one alphabetically.
+                a.as_str().cmp(b.as_str()).reverse()
+            }
+        }
+    }
+}
+
+/// Whether the range name `a` covers the concrete name `b`: a wildcard `a` matches anything,
+/// otherwise the names must be equal.
+fn names_match(a: Name, b: Name) -> bool {
+    a == mime::STAR || compare_names(a, b) == Ordering::Equal
+}
+
+impl MediaType {
+    /// Does this media range match the given concrete media type?
+    ///
+    /// A range matches when its type is `*` or case-insensitively equal to the concrete type,
+    /// and its subtype is `*` or case-insensitively equal to the concrete subtype, and every
+    /// non-`q` parameter on the range is present on the concrete type with an equal value.
+    ///
+    /// This is the building block [`Accept::negotiate`](crate::Accept::negotiate) is built on; it's
+    /// exposed directly for callers that want to test a type against a range without performing
+    /// full negotiation, e.g. custom routing.
+    ///
+    /// ```
+    /// use accept_header::MediaType;
+    /// use mime::TEXT_HTML;
+    ///
+    /// let range: MediaType = "text/*".parse::<mime::Mime>().unwrap().into();
+    /// assert!(range.matches(&TEXT_HTML));
+    /// ```
+    pub fn matches(&self, concrete: &Mime) -> bool {
+        names_match(self.mime.type_(), concrete.type_())
+            && names_match(self.mime.subtype(), concrete.subtype())
+            && self
+                .mime
+                .params()
+                .filter(|(key, _value)| key != &"q")
+                .all(|(key, value)| concrete.get_param(key) == Some(value))
+    }
+
+    /// How specific this media range is, independent of its quality: exact `type/subtype` beats
+    /// `type/*` beats `*/*`, with ties broken by `specificity` (parameter count).
+    ///
+    /// Used to select the best-matching range among several that all [`matches`](Self::matches)
+    /// the same concrete media type.
+    pub(crate) fn precedence(&self) -> u32 {
+        let exactness = match (self.mime.type_(), self.mime.subtype()) {
+            (mime::STAR, _) => 0,
+            (_, mime::STAR) => 1,
+            _ => 2,
+        };
+        exactness * 256 + u32::from(self.specificity)
+    }
+}
+
+impl RangeSpec for MediaType {
+    type Concrete = Mime;
+
+    fn quality_factor(&self) -> Quality {
+        self.quality_factor()
+    }
+
+    fn matches(&self, concrete: &Mime) -> bool {
+        self.matches(concrete)
+    }
+
+    fn precedence(&self) -> u32 {
+        self.precedence()
+    }
+}
+
 impl Ord for MediaType {
     /// Media types compare by the following rules:
     ///
@@ -31,36 +127,6 @@ impl Ord for MediaType {
     ///     - wildcard outer types are less than any explicit type
     ///     - we reverse the essence str otherwise to conform to the intuition that the alphabetically lowest value is the first considered
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        /// Compare two mime names, returning an ordering.
-        ///
-        /// We can't compare them directly because the derived implementation doesn't
-        /// respect case insensitivity, and also doesn't handle the "wildcard is least"
-        /// rule that we want.
-        fn compare_names(a: Name, b: Name) -> Ordering {
-            match (a, b) {
-                (mime::STAR, mime::STAR) => Ordering::Equal,
-                (mime::STAR, _) => Ordering::Less,
-                (_, mime::STAR) => Ordering::Greater,
-                (a, b) => {
-                    // this _may_ be equal even if the source strings are unequal, if it happens that
-                    // we have a case-insensitive comparison. Unfortunately, the library doesn't expose
-                    // that information to us directly, so we have to explicitly compare for equality before
-                    // performing an ordering comparison.
-                    //
-                    // ... the _only_ way to get at a safe public test which respects case insensitivity when appropriate
-                    // is to compare a `Name` to a `&str`.
-                    if a == b.as_str() {
-                        Ordering::Equal
-                    } else {
-                        // if they're unequal, by whatever metric, it's fine to fall back to string comparison.
-                        // note though that we reverse the output: this lets us sort a list of media types, and the
-                        // best one (all else being equal) is the first one alphabetically.
-                        a.as_str().cmp(b.as_str()).reverse()
-                    }
-                }
-            }
-        }
-
         self.quality_factor()
             .cmp(&other.quality_factor())
             .then_with(|| self.specificity.cmp(&other.specificity))
@@ -82,6 +148,35 @@ impl PartialOrd for MediaType {
     }
 }
 
+/// The number of non-`q` parameters on a `Mime`.
+///
+/// technically, this is a bug: if two mime types have more than 255 parameters, they might compare as equal when they are not.
+/// it is an unlikely enough scenario that I do not intend to fix it.
+fn specificity_of(mime: &Mime) -> u8 {
+    mime.params()
+        .filter(|(key, _value)| key != &"q")
+        .count()
+        .try_into()
+        .unwrap_or(u8::MAX)
+}
+
+impl MediaType {
+    /// Construct a `MediaType` from a `Mime` and an explicit quality weight.
+    ///
+    /// The weight is clamped to the valid `[0.0..=1.0]` range. `specificity` is derived from the
+    /// mime's own parameters, same as when [parsing](crate::Accept::parse_body) a header.
+    pub fn new(mime: Mime, quality: Option<Quality>) -> Self {
+        let quality = quality.map(|q| Quality::new(q.raw().clamp(0.0, 1.0)));
+        let specificity = specificity_of(&mime);
+
+        MediaType {
+            mime,
+            quality,
+            specificity,
+        }
+    }
+}
+
 impl From<Mime> for MediaType {
     fn from(mime: Mime) -> Self {
         let quality = mime
@@ -91,14 +186,7 @@ impl From<Mime> for MediaType {
             .map(|f| f.clamp(0.0, 1.0))
             .and_then(Quality::try_new);
 
-        // technically, this is a bug: if two mime types have more than 255 parameters, they might compare as equal when they are not.
-        // it is an unlikely enough scenario that I do not intend to fix it.
-        let specificity = mime
-            .params()
-            .filter(|(key, _value)| key != &"q")
-            .count()
-            .try_into()
-            .unwrap_or(u8::MAX);
+        let specificity = specificity_of(&mime);
 
         MediaType {
             mime,
@@ -110,7 +198,14 @@ impl From<Mime> for MediaType {
 
 impl fmt::Display for MediaType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self { mime, .. } = self;
-        write!(f, "{mime}")
+        write!(f, "{}", self.mime)?;
+        // if the mime itself already carries a `q` parameter, it's already been written above;
+        // only append our own quality when the mime didn't speak for itself.
+        if self.mime.get_param("q").is_none() {
+            if let Some(quality) = self.quality {
+                write!(f, ";q={quality}")?;
+            }
+        }
+        Ok(())
     }
 }
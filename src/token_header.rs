@@ -0,0 +1,153 @@
+use std::fmt;
+
+use bstr::BString;
+use headers_core::HeaderValue;
+
+use crate::coding::{Coding, CodingParseError};
+use crate::weighted_list::WeightedList;
+use crate::Quality;
+
+/// Shared implementation for headers that are simply a comma-separated, `q`-weighted list of
+/// tokens: `Accept-Encoding` and `Accept-Charset`.
+#[derive(Debug, Clone)]
+pub(crate) struct TokenHeader {
+    header_name: &'static str,
+    items: WeightedList<Coding>,
+}
+
+impl TokenHeader {
+    /// Parse the complete header, including the header name.
+    pub(crate) fn parse(header_name: &'static str, header: &str) -> Result<Self, ParseError> {
+        let header_idx = header_name.len();
+        let header_colon_idx = header_idx + 1;
+
+        // work on the bytes level to avoid panics for multi-byte characters
+        let expect_substr = &header.as_bytes()[..header_idx.min(header.len())];
+        if !expect_substr.eq_ignore_ascii_case(header_name.as_bytes()) {
+            return Err(ParseError::WrongHeader {
+                expected: header_name,
+                got: expect_substr.into(),
+            });
+        }
+
+        if !header.is_char_boundary(header_colon_idx) {
+            return Err(ParseError::BodyIndexNotOnCharacterBoundary);
+        }
+
+        Self::parse_body(header_name, &header[header_colon_idx..])
+    }
+
+    /// Parse the header body, excluding the header name.
+    pub(crate) fn parse_body(header_name: &'static str, body: &str) -> Result<Self, ParseError> {
+        let mut items = Vec::new();
+        for input in body.split(',') {
+            let input = input.trim();
+            let coding = input
+                .parse::<Coding>()
+                .map_err(|err| ParseError::FailedToParseCoding {
+                    input: input.into(),
+                    err,
+                })?;
+            items.push(coding);
+        }
+
+        Ok(Self {
+            header_name,
+            items: WeightedList::new(items),
+        })
+    }
+
+    /// Build a `TokenHeader` from a collection of codings, sorting them into the
+    /// descending-quality order this type maintains internally.
+    pub(crate) fn new(header_name: &'static str, items: impl IntoIterator<Item = Coding>) -> Self {
+        Self {
+            header_name,
+            items: WeightedList::new(items),
+        }
+    }
+
+    /// Insert a coding, maintaining descending-quality order.
+    pub(crate) fn push(&mut self, item: Coding) {
+        self.items.push(item);
+    }
+
+    pub(crate) fn iter(&self) -> impl '_ + Iterator<Item = &Coding> {
+        self.items.iter()
+    }
+
+    pub(crate) fn negotiate_with_quality<'a>(
+        &self,
+        offered: &'a [String],
+    ) -> Option<(&'a String, Quality)> {
+        self.items.negotiate(offered)
+    }
+
+    fn fmt_body(&self) -> FormatBody<'_> {
+        FormatBody {
+            items: self.items.as_slice(),
+        }
+    }
+
+    pub(crate) fn body_to_string(&self) -> String {
+        self.fmt_body().to_string()
+    }
+
+    pub(crate) fn decode<'i, I>(
+        header_name: &'static str,
+        values: &mut I,
+    ) -> Result<Self, headers_core::Error>
+    where
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        let value = values.next().ok_or_else(headers_core::Error::invalid)?;
+        let value_str = value.to_str().map_err(|_| headers_core::Error::invalid())?;
+        Self::parse_body(header_name, value_str).map_err(|_| headers_core::Error::invalid())
+    }
+
+    pub(crate) fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+        let header_value = HeaderValue::from_str(&self.body_to_string())
+            .expect("header canonical form includes only visible ascii chars");
+        values.extend(std::iter::once(header_value));
+    }
+}
+
+impl fmt::Display for TokenHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.header_name)?;
+        self.fmt_body().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FormatBody<'a> {
+    items: &'a [Coding],
+}
+
+impl<'a> fmt::Display for FormatBody<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for item in self.items {
+            if first {
+                first = false;
+            } else {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("wrong header name: expect \"{expected}\"; have \"{got}\"")]
+    WrongHeader { expected: &'static str, got: BString },
+    #[error("body index does not fall on a character boundary")]
+    BodyIndexNotOnCharacterBoundary,
+    #[error("failed to parse token \"{input}\"")]
+    FailedToParseCoding {
+        input: String,
+        #[source]
+        err: CodingParseError,
+    },
+}
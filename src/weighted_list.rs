@@ -0,0 +1,86 @@
+use crate::Quality;
+
+/// A quality-sorted list of weighted ranges, as maintained by `Accept`-family headers.
+///
+/// The list is always kept in descending order of quality, the same invariant
+/// `Accept::parse_body` establishes for media types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct WeightedList<T> {
+    items: Vec<T>,
+}
+
+impl<T: Ord> WeightedList<T> {
+    /// Build a list from unsorted items, sorting them into descending-quality order.
+    pub(crate) fn new(items: impl IntoIterator<Item = T>) -> Self {
+        let mut items: Vec<T> = items.into_iter().collect();
+        items.sort();
+        items.reverse();
+        Self { items }
+    }
+
+    /// Insert an item, maintaining descending-quality order.
+    pub(crate) fn push(&mut self, item: T) {
+        let idx = self.items.partition_point(|existing| existing >= &item);
+        self.items.insert(idx, item);
+    }
+
+    pub(crate) fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+}
+
+/// A weighted range that can be tested against a concrete offered value, enabling server-side
+/// content negotiation (see [`Accept::negotiate`](crate::Accept::negotiate)).
+pub(crate) trait RangeSpec: Ord {
+    /// The concrete value type this range can be matched against.
+    type Concrete;
+
+    fn quality_factor(&self) -> Quality;
+
+    /// Does this range cover the given concrete value?
+    fn matches(&self, concrete: &Self::Concrete) -> bool;
+
+    /// How specific this range is, independent of quality. Used to pick the best match among
+    /// several ranges that all [`matches`](Self::matches) the same concrete value.
+    fn precedence(&self) -> u32;
+}
+
+impl<T: RangeSpec> WeightedList<T> {
+    /// Perform content negotiation: for each offered value, find the most specific range that
+    /// matches it and score it by that range's quality; a matching range with quality `0.0`
+    /// excludes the value. Return the highest-scoring offered value, breaking ties in favor of
+    /// the value appearing earliest in `offered` (server preference wins).
+    pub(crate) fn negotiate<'a>(
+        &self,
+        offered: &'a [T::Concrete],
+    ) -> Option<(&'a T::Concrete, Quality)> {
+        let mut best: Option<(&'a T::Concrete, Quality)> = None;
+        for concrete in offered {
+            let Some(range) = self
+                .items
+                .iter()
+                .filter(|range| range.matches(concrete))
+                .max_by_key(|range| range.precedence())
+            else {
+                continue;
+            };
+
+            let score = range.quality_factor();
+            if score == Quality::new(0.0) {
+                continue;
+            }
+
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_score)| score > *best_score)
+            {
+                best = Some((concrete, score));
+            }
+        }
+        best
+    }
+}